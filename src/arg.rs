@@ -1,3 +1,7 @@
+use std::any::Any;
+use std::str::FromStr;
+
+use crate::error::Error;
 use crate::matcher::{Matcher};
 
 pub trait ArgProperties<O, P> {
@@ -12,7 +16,14 @@ pub struct OptionProperties<'a, O, P> {
     pub arg_index: usize,
     pub option_index: usize,
     pub code: String,
-    pub value_text: Option<String>,
+    /// The values captured for this option, in the order they appeared. Empty when the option took no value.
+    /// Holds more than one entry when the matcher's [`value_count_max`](crate::Matcher::value_count_max)
+    /// allows gathering multiple whitespace-separated values, e.g. `--coords x y z`.
+    pub value_text: Vec<String>,
+    /// Number of times this option was matched. Only incremented past `1` when the matcher's
+    /// [`Action`](crate::matcher::Action) is `Count`, in which case repeated occurrences are collapsed into
+    /// this single [`OptionProperties`] rather than emitting one [`Arg::Option`] per occurrence.
+    pub count: usize,
 }
 
 impl<'a, O, P> ArgProperties<O, P> for OptionProperties<'a, O, P> {
@@ -27,6 +38,50 @@ impl<'a, O, P> ArgProperties<O, P> for OptionProperties<'a, O, P> {
     }
 }
 
+impl<'a, O, P> OptionProperties<'a, O, P> {
+    /// Parses this option's first captured value using the [`Matcher::value_parser`](Matcher::value_parser)
+    /// configured on the matcher that recognized this option.
+    pub fn get_value<T: 'static>(&self) -> Result<T, String> {
+        let text = self.value_text.first().ok_or_else(|| {
+            Error::ValueParseFailed.to_text(Some(&format!("arg {}, char {}: option has no value", self.arg_index, self.line_char_index)))
+        })?;
+
+        parse_value(self.matcher, text, self.arg_index, self.line_char_index)
+    }
+
+    /// Parses every value captured for this option (see [`Matcher::value_count_max`](crate::Matcher::value_count_max))
+    /// using the [`Matcher::value_parser`](Matcher::value_parser) configured on the matcher that recognized it.
+    pub fn get_values<T: 'static>(&self) -> Result<Vec<T>, String> {
+        self.value_text.iter()
+            .map(|text| parse_value(self.matcher, text, self.arg_index, self.line_char_index))
+            .collect()
+    }
+
+    /// Parses this option's first captured value via `T`'s [`FromStr`] implementation, without needing a
+    /// [`Matcher::value_parser`](Matcher::value_parser) configured. Useful for one-off types like `u16` or
+    /// [`PathBuf`](std::path::PathBuf) that already implement `FromStr`.
+    pub fn value_as<T: FromStr>(&self) -> Result<T, String>
+    where
+        T::Err: std::fmt::Display,
+    {
+        let text = self.value_text.first().ok_or_else(|| {
+            Error::ValueParseFailed.to_text(Some(&format!("arg {}, char {}: option has no value", self.arg_index, self.line_char_index)))
+        })?;
+
+        parse_value_as(text, self.arg_index, self.line_char_index)
+    }
+
+    /// Parses every value captured for this option via `T`'s [`FromStr`] implementation. See [`value_as`](Self::value_as).
+    pub fn values_as<T: FromStr>(&self) -> Result<Vec<T>, String>
+    where
+        T::Err: std::fmt::Display,
+    {
+        self.value_text.iter()
+            .map(|text| parse_value_as(text, self.arg_index, self.line_char_index))
+            .collect()
+    }
+}
+
 pub struct ParamProperties<'a, O, P> {
     pub matcher: &'a Matcher<O, P>,
     pub line_char_index: usize,
@@ -47,9 +102,72 @@ impl<'a, O, P> ArgProperties<O, P> for ParamProperties<'a, O, P> {
     }
 }
 
+impl<'a, O, P> ParamProperties<'a, O, P> {
+    /// Parses this parameter's value text using the [`Matcher::value_parser`](Matcher::value_parser) configured
+    /// on the matcher that recognized this parameter.
+    pub fn get_value<T: 'static>(&self) -> Result<T, String> {
+        parse_value(self.matcher, &self.value_text, self.arg_index, self.line_char_index)
+    }
+
+    /// Parses this parameter's value via `T`'s [`FromStr`] implementation, without needing a
+    /// [`Matcher::value_parser`](Matcher::value_parser) configured. Useful for one-off types like `u16` or
+    /// [`PathBuf`](std::path::PathBuf) that already implement `FromStr`.
+    pub fn value_as<T: FromStr>(&self) -> Result<T, String>
+    where
+        T::Err: std::fmt::Display,
+    {
+        parse_value_as(&self.value_text, self.arg_index, self.line_char_index)
+    }
+}
+
+fn parse_value<O, P, T: 'static>(matcher: &Matcher<O, P>, text: &str, arg_index: usize, line_char_index: usize) -> Result<T, String> {
+    let parser = matcher.value_parser.as_ref().ok_or_else(|| {
+        Error::ValueParseFailed.to_text(Some(&format!("arg {}, char {}: no value parser configured", arg_index, line_char_index)))
+    })?;
+
+    let parsed: Box<dyn Any> = parser(text).map_err(|message| {
+        Error::ValueParseFailed.to_text(Some(&format!("arg {}, char {}: {}", arg_index, line_char_index, message)))
+    })?;
+
+    parsed.downcast::<T>().map(|value| *value).map_err(|_| {
+        Error::ValueParseFailed.to_text(Some(&format!("arg {}, char {}: parsed value is not of the requested type", arg_index, line_char_index)))
+    })
+}
+
+fn parse_value_as<T: FromStr>(text: &str, arg_index: usize, line_char_index: usize) -> Result<T, String>
+where
+    T::Err: std::fmt::Display,
+{
+    text.parse::<T>().map_err(|error| {
+        Error::ValueParseFailed.to_text(Some(&format!("arg {}, char {}: could not parse \"{}\": {}", arg_index, line_char_index, text, error)))
+    })
+}
+
+/// A parameter whose text matched a registered subcommand name, handing matching of every following
+/// argument over to that subcommand's own [`Parser`](crate::Parser).
+pub struct CommandProperties<'a, O, P> {
+    pub matcher: &'a Matcher<O, P>,
+    pub line_char_index: usize,
+    pub arg_index: usize,
+    pub name: String,
+}
+
+impl<'a, O, P> ArgProperties<O, P> for CommandProperties<'a, O, P> {
+    fn get_matcher(&self) -> &Matcher<O, P> {
+        self.matcher
+    }
+    fn get_line_char_index(&self) -> usize {
+        self.line_char_index
+    }
+    fn get_arg_index(&self) -> usize {
+        self.arg_index
+    }
+}
+
 pub enum Arg<'a, O, P> {
     Param(ParamProperties<'a, O, P>),
     Option(OptionProperties<'a, O, P>),
+    Command(CommandProperties<'a, O, P>),
 }
 
 pub type Args<'a, O, P> = Vec<Arg<'a, O, P>>;
\ No newline at end of file
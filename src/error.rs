@@ -0,0 +1,53 @@
+/// Identifies the kind of problem encountered while parsing a command line.
+pub enum ErrorId {
+    NoCodeAfterOptionAnnouncer,
+    OptionCodeMissingDoubleAnnouncer,
+    ParamMissingClosingQuoteCharacter,
+    InvalidEscapedCharacterInParam,
+    QuotedParamNotFollowedByWhitespaceChar,
+    NoMatchSupportsValueForOptionCode,
+    OptionValueMissingClosingQuoteCharacter,
+    InvalidEscapedCharacterInOptionValue,
+    QuotedOptionValueNotFollowedByWhitespaceChar,
+    OptionValueCannotBeginWithOptionAnnouncer,
+    UnmatchedOption,
+    UnmatchedParam,
+    UnmatchedValue,
+    InsufficientOptionValues,
+    RequiredDependencyMissing,
+    ConflictingOptionsMatched,
+    RequiredOptionMissing,
+    ValueParseFailed,
+}
+
+pub use ErrorId as Error;
+
+impl ErrorId {
+    pub fn to_text(&self, extra: Option<&str>) -> String {
+        let message = match self {
+            ErrorId::NoCodeAfterOptionAnnouncer => "No option code found after option announcer character",
+            ErrorId::OptionCodeMissingDoubleAnnouncer => "Option code requires a double announcer character",
+            ErrorId::ParamMissingClosingQuoteCharacter => "Parameter is missing its closing quote character",
+            ErrorId::InvalidEscapedCharacterInParam => "Invalid escaped character in parameter",
+            ErrorId::QuotedParamNotFollowedByWhitespaceChar => "Quoted parameter is not followed by a whitespace character",
+            ErrorId::NoMatchSupportsValueForOptionCode => "No matcher supports a value for this option code",
+            ErrorId::OptionValueMissingClosingQuoteCharacter => "Option value is missing its closing quote character",
+            ErrorId::InvalidEscapedCharacterInOptionValue => "Invalid escaped character in option value",
+            ErrorId::QuotedOptionValueNotFollowedByWhitespaceChar => "Quoted option value is not followed by a whitespace character",
+            ErrorId::OptionValueCannotBeginWithOptionAnnouncer => "Option value cannot begin with the option announcer character",
+            ErrorId::UnmatchedOption => "Could not find a matcher for this option",
+            ErrorId::UnmatchedParam => "Could not find a matcher for this parameter",
+            ErrorId::UnmatchedValue => "Value is not one of the allowed values for this matcher",
+            ErrorId::InsufficientOptionValues => "Option did not receive enough values",
+            ErrorId::RequiredDependencyMissing => "A matched option requires another option which was not matched",
+            ErrorId::ConflictingOptionsMatched => "Two matched options conflict with each other",
+            ErrorId::RequiredOptionMissing => "A required option was not matched",
+            ErrorId::ValueParseFailed => "Value could not be parsed to the requested type",
+        };
+
+        match extra {
+            Some(extra) => format!("{}: {}", message, extra),
+            None => message.to_string(),
+        }
+    }
+}
@@ -30,12 +30,23 @@ pub use matcher:: {
     OptionHasValue,
     ArgType,
     DEFAULT_OPTION_HAS_VALUE,
+    Action,
+    DEFAULT_ACTION,
+    DEFAULT_IS_REQUIRED,
+    ValueParser,
+    i64_value_parser,
+    u64_value_parser,
+    f64_value_parser,
+    bool_value_parser,
+    path_buf_value_parser,
+    possible_values_value_parser,
 };
 
 pub use arg::{
     ArgProperties,
     OptionProperties,
     ParamProperties,
+    CommandProperties,
     Arg,
     Args,
 };
@@ -46,6 +57,9 @@ pub use parser::{
     DEFAULT_OPTION_ANNOUNCER_CHARS,
     DEFAULT_OPTION_CODES_CASE_SENSITIVE,
     DEFAULT_MULTI_CHAR_OPTION_CODE_REQUIRES_DOUBLE_ANNOUNCER,
+    DEFAULT_SHORT_OPTION_BUNDLING_ENABLED,
+    DEFAULT_NEGATIVE_NUMBERS_CAN_BE_PARAMS,
+    DEFAULT_ALLOW_HYPHEN_VALUES,
     DEFAULT_OPTION_VALUE_ANNOUNCER_CHARS,
     DEFAULT_OPTION_VALUES_CASE_SENSITIVE,
     DEFAULT_PARAMS_CASE_SENSITIVE,
@@ -0,0 +1,192 @@
+use std::any::Any;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use crate::regex_or_text::RegexOrText;
+
+/// The tag type used by [`Matcher`] when a caller does not need to associate their own enum with matchers.
+pub type DefaultTagType = ();
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum OptionOrParameter {
+    Option,
+    Parameter,
+}
+
+/// Distinguishes whether a [`Matcher`] is used to recognize an option argument or a parameter argument.
+pub enum ArgType {
+    Option,
+    Param,
+}
+
+/// Controls whether, and how, an option matched by a [`Matcher`] can carry a value.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum OptionHasValue {
+    Never,
+    IfPossible,
+    AlwaysButValueMustNotStartWithOptionAnnouncer,
+    AlwaysAndValueCanStartWithOptionAnnouncer,
+}
+
+pub const DEFAULT_OPTION_HAS_VALUE: OptionHasValue = OptionHasValue::IfPossible;
+
+/// Controls what a matched option produces.
+///
+/// `Store` is the default: the option's value text (if any) is captured as usual. `Flag` and `Count` never
+/// consume a value, even when [`Matcher::option_has_value`](Matcher::option_has_value) would otherwise allow
+/// one; repeated occurrences of a `Count` option are collapsed into a single [`OptionProperties`](crate::OptionProperties)
+/// whose `count` is incremented instead of emitting a new [`Arg::Option`](crate::Arg::Option) per occurrence.
+/// `SetTrue`/`SetFalse` also never consume a value; they exist so callers can distinguish boolean on/off
+/// switches from bare flags when deciding how to interpret a match.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Action {
+    Store,
+    Flag,
+    Count,
+    SetTrue,
+    SetFalse,
+}
+
+pub const DEFAULT_ACTION: Action = Action::Store;
+
+/// Default for [`Matcher.is_required`](Matcher::is_required): not required.
+pub const DEFAULT_IS_REQUIRED: bool = false;
+
+/// A type-erased value parser, run against the raw value text captured by a [`Matcher`].
+///
+/// Returns the parsed value boxed as [`Any`] so that [`OptionProperties::get_value`](crate::OptionProperties::get_value)
+/// and [`ParamProperties::get_value`](crate::ParamProperties::get_value) can downcast it to the caller's
+/// requested type, or an error message describing why the text could not be parsed.
+pub type ValueParser = Rc<dyn Fn(&str) -> Result<Box<dyn Any>, String>>;
+
+fn parse_with<T: 'static + std::str::FromStr>() -> ValueParser
+where
+    T::Err: std::fmt::Display,
+{
+    Rc::new(|text| {
+        text.parse::<T>()
+            .map(|value| Box::new(value) as Box<dyn Any>)
+            .map_err(|error| error.to_string())
+    })
+}
+
+/// Built-in [`ValueParser`] for `i64` values.
+pub fn i64_value_parser() -> ValueParser {
+    parse_with::<i64>()
+}
+
+/// Built-in [`ValueParser`] for `u64` values.
+pub fn u64_value_parser() -> ValueParser {
+    parse_with::<u64>()
+}
+
+/// Built-in [`ValueParser`] for `f64` values.
+pub fn f64_value_parser() -> ValueParser {
+    parse_with::<f64>()
+}
+
+/// Built-in [`ValueParser`] for `bool` values (accepts anything `"true"`/`"false"` parse as).
+pub fn bool_value_parser() -> ValueParser {
+    parse_with::<bool>()
+}
+
+/// Built-in [`ValueParser`] which accepts any text as a [`PathBuf`] without further validation.
+pub fn path_buf_value_parser() -> ValueParser {
+    Rc::new(|text| Ok(Box::new(PathBuf::from(text)) as Box<dyn Any>))
+}
+
+/// Built-in [`ValueParser`] which restricts a value to one of `possible_values`, honoring `case_sensitive`.
+pub fn possible_values_value_parser(possible_values: Vec<String>, case_sensitive: bool) -> ValueParser {
+    Rc::new(move |text| {
+        let is_allowed = possible_values.iter().any(|possible_value| {
+            if case_sensitive {
+                possible_value == text
+            } else {
+                possible_value.eq_ignore_ascii_case(text)
+            }
+        });
+
+        if is_allowed {
+            Ok(Box::new(text.to_string()) as Box<dyn Any>)
+        } else {
+            Err(format!("\"{}\" is not one of the allowed values: {}", text, possible_values.join(", ")))
+        }
+    })
+}
+
+/// A rule used to recognize an argument in a command line and, once recognized, tag it with caller-supplied
+/// values.
+///
+/// `O` is the tag type attached to a matcher when it matches an option. `P` is the tag type attached to a
+/// matcher when it matches a parameter. Most fields are `None` when the matcher should not restrict matching
+/// on that criteria.
+pub struct Matcher<O = DefaultTagType, P = DefaultTagType> {
+    pub name: String,
+    pub arg_indices: Option<Vec<usize>>,
+    pub option_or_parameter: Option<OptionOrParameter>,
+    pub option_indices: Option<Vec<usize>>,
+    pub option_codes: Option<Vec<RegexOrText>>,
+    pub option_has_value: Option<OptionHasValue>,
+    pub action: Option<Action>,
+    pub option_tag: Option<O>,
+    pub param_indices: Option<Vec<usize>>,
+    pub param_tag: Option<P>,
+    pub value_text: Option<RegexOrText>,
+    /// Restricts the matched value to one of a closed set of allowed literals/patterns, like clap's
+    /// `possible_values`. A value that matches none of these produces [`ErrorId::UnmatchedValue`](crate::ErrorId::UnmatchedValue)
+    /// instead of being silently rejected as a non-match for this matcher.
+    pub value_possible_values: Option<Vec<RegexOrText>>,
+    /// Minimum total number of whitespace-separated values this option must gather, including the first.
+    /// Unset defaults to `1`. See [`value_count_max`](Matcher::value_count_max).
+    pub value_count_min: Option<usize>,
+    /// Maximum total number of whitespace-separated values this option will gather before leaving the rest
+    /// of the command line to be matched normally. Unset defaults to `1` (today's single-value behaviour)
+    /// unless [`value_count_min`](Matcher::value_count_min) is set, in which case gathering is unbounded.
+    ///
+    /// When greater than `1`, every param-like argument following a matched value is folded into this
+    /// option's [`OptionProperties::value_text`](crate::OptionProperties) until the maximum is reached or the
+    /// next option announcer is encountered, e.g. `--coords x y z`.
+    pub value_count_max: Option<usize>,
+    pub value_parser: Option<ValueParser>,
+    /// Other option tags that must also be matched whenever this matcher is matched. Checked by
+    /// [`Parser::validate`](crate::Parser::validate) after parsing, reported as
+    /// [`ErrorId::RequiredDependencyMissing`](crate::ErrorId::RequiredDependencyMissing) if absent.
+    pub requires: Option<Vec<O>>,
+    /// Other option tags that must not also be matched whenever this matcher is matched. Checked by
+    /// [`Parser::validate`](crate::Parser::validate) after parsing, reported as
+    /// [`ErrorId::ConflictingOptionsMatched`](crate::ErrorId::ConflictingOptionsMatched) if both are present.
+    pub conflicts_with: Option<Vec<O>>,
+    /// When `true`, [`Parser::validate`](crate::Parser::validate) requires this matcher's
+    /// [`option_tag`](Matcher::option_tag) to appear among the matched options, reported as
+    /// [`ErrorId::RequiredOptionMissing`](crate::ErrorId::RequiredOptionMissing) otherwise.
+    ///
+    /// Default: `false`
+    pub is_required: bool,
+}
+
+impl<O, P> Matcher<O, P> {
+    pub fn new(name: String) -> Self {
+        Matcher {
+            name,
+            arg_indices: None,
+            option_or_parameter: None,
+            option_indices: None,
+            option_codes: None,
+            option_has_value: None,
+            action: None,
+            option_tag: None,
+            param_indices: None,
+            param_tag: None,
+            value_text: None,
+            value_possible_values: None,
+            value_count_min: None,
+            value_count_max: None,
+            value_parser: None,
+            requires: None,
+            conflicts_with: None,
+            is_required: DEFAULT_IS_REQUIRED,
+        }
+    }
+}
+
+pub type Matchers<O, P> = Vec<Matcher<O, P>>;
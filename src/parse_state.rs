@@ -0,0 +1,98 @@
+use crate::error::Error;
+
+#[derive(PartialEq, Eq)]
+pub(crate) enum ArgParseState {
+    NotInArg,
+    InParam,
+    InParamPossibleEndQuote,
+    InParamEscaped,
+    InOption,
+}
+
+#[derive(PartialEq, Eq)]
+pub(crate) enum OptionParseState {
+    Announced,
+    InCode,
+    WaitOptionValue,
+    InValue,
+    InValuePossibleEndQuote,
+    InValueEscaped,
+}
+
+pub(crate) struct ParseState {
+    pub(crate) multi_char_option_code_requires_double_announcer: bool,
+    /// Mirrors `Parser.short_option_bundling_enabled`, so `set_option_code` can leave a single-announced
+    /// multi-char code for the bundling-candidate check instead of always erroring on it.
+    pub(crate) short_option_bundling_enabled: bool,
+    pub(crate) line_len: usize,
+    pub(crate) arg_parse_state: ArgParseState,
+    pub(crate) option_parse_state: OptionParseState,
+    pub(crate) arg_line_char_idx: usize,
+    pub(crate) start_idx: usize,
+    pub(crate) option_announcer_char: char,
+    pub(crate) option_code: String,
+    pub(crate) option_value_announcer_is_ambiguous: bool,
+    pub(crate) current_option_value_may_be_param: bool,
+    pub(crate) value_quoted: bool,
+    pub(crate) value_bldr: String,
+    pub(crate) option_termination_chars: Vec<char>,
+    pub(crate) arg_count: usize,
+    pub(crate) option_count: usize,
+    pub(crate) param_count: usize,
+    /// Maps an option code to the index, within the in-progress `args` vector, of the `Arg::Option` entry
+    /// used to accumulate occurrences of a `Count` action matcher.
+    pub(crate) count_action_arg_indices: std::collections::HashMap<String, usize>,
+    /// Set by `match_param_arg` when a parameter's text matches a registered subcommand name; consumed by
+    /// `Parser::parse` to switch the active matcher set over to that subcommand's child `Parser`.
+    pub(crate) pending_subcommand_switch: Option<String>,
+    /// `args` index of the `Arg::Option` entry currently gathering additional whitespace-separated values
+    /// (see `Matcher::value_count_max`); `None` when no option is in a value-gathering state.
+    pub(crate) multi_value_option_arg_index: Option<usize>,
+    /// Total number of values gathered so far (including the first) for `multi_value_option_arg_index`.
+    pub(crate) multi_value_total: usize,
+    /// Minimum total value count required by the matcher at `multi_value_option_arg_index`.
+    pub(crate) multi_value_min: usize,
+    /// Maximum total value count accepted by the matcher at `multi_value_option_arg_index`.
+    pub(crate) multi_value_max: usize,
+}
+
+impl ParseState {
+    pub(crate) fn set_option_code(&mut self, line: &str, ending_char_idx: Option<usize>) -> Result<(), String> {
+        let ending_idx = ending_char_idx.unwrap_or(self.line_len);
+        let raw_option_code: String = line.chars().skip(self.start_idx).take(ending_idx - self.start_idx).collect();
+
+        let mut raw_option_chars = raw_option_code.chars();
+        match raw_option_chars.next() {
+            None => {
+                self.option_code = String::from("");
+                Ok(())
+            }
+            Some(first_char) => {
+                if !self.multi_char_option_code_requires_double_announcer {
+                    self.option_code = raw_option_code;
+                    Ok(())
+                } else {
+                    let first_char_is_announcer = first_char == self.option_announcer_char;
+                    let has_more_than_one_char = raw_option_chars.next().is_some();
+                    if !has_more_than_one_char {
+                        self.option_code = if first_char_is_announcer { String::from("") } else { raw_option_code };
+                        Ok(())
+                    } else if first_char_is_announcer {
+                        self.option_code = raw_option_code.chars().skip(1).collect();
+                        Ok(())
+                    } else {
+                        self.option_code = raw_option_code.clone();
+                        if self.short_option_bundling_enabled {
+                            // Leave this for `Parser::match_bundled_option_codes` to expand instead of
+                            // erroring; it reports `OptionCodeMissingDoubleAnnouncer` itself if a character
+                            // of the cluster is not a registered option code.
+                            Ok(())
+                        } else {
+                            Err(Error::OptionCodeMissingDoubleAnnouncer.to_text(Some(&raw_option_code)))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::error::Error;
 use crate::regex_or_text::{RegexOrText};
-use crate::arg::{Arg, Args, OptionProperties, ParamProperties};
-use crate::matcher::{Matcher, Matchers, OptionHasValue, DefaultTagType, DEFAULT_OPTION_HAS_VALUE, OptionOrParameter};
+use crate::arg::{Arg, Args, OptionProperties, ParamProperties, CommandProperties};
+use crate::matcher::{Matcher, Matchers, OptionHasValue, DefaultTagType, DEFAULT_OPTION_HAS_VALUE, OptionOrParameter, Action, DEFAULT_ACTION};
+use std::collections::HashMap;
 use crate::parse_state::{ParseState, ArgParseState, OptionParseState};
 
 pub enum EmbedQuoteCharMethod {
@@ -14,6 +15,15 @@ pub const DEFAULT_QUOTE_CHAR: char = '"';
 pub const DEFAULT_OPTION_ANNOUNCER_CHARS: [char; 1] = ['-'];
 pub const DEFAULT_OPTION_CODES_CASE_SENSITIVE: bool = false;
 pub const DEFAULT_MULTI_CHAR_OPTION_CODE_REQUIRES_DOUBLE_ANNOUNCER: bool = false;
+/// Default for [`Parser.negative_numbers_can_be_params`](Parser::negative_numbers_can_be_params): disabled,
+/// preserving today's behaviour of always treating a leading announcer character as the start of an option.
+pub const DEFAULT_NEGATIVE_NUMBERS_CAN_BE_PARAMS: bool = false;
+/// Default for [`Parser.allow_hyphen_values`](Parser::allow_hyphen_values): disabled, preserving today's
+/// behaviour of erroring when a value's first character is the option announcer.
+pub const DEFAULT_ALLOW_HYPHEN_VALUES: bool = false;
+/// Default for [`Parser.short_option_bundling_enabled`](Parser::short_option_bundling_enabled): disabled, since
+/// it conflicts with multi-char single-announcer codes.
+pub const DEFAULT_SHORT_OPTION_BUNDLING_ENABLED: bool = false;
 pub const DEFAULT_OPTION_VALUE_ANNOUNCER_CHARS: [char; 1] = [' '];
 pub const DEFAULT_OPTION_VALUES_CASE_SENSITIVE: bool = false;
 pub const DEFAULT_OPTION_VALUES_CAN_START_WITH_OPTION_ANNOUNCER_CHAR: bool = false;
@@ -49,6 +59,37 @@ pub struct Parser<O: Default = DefaultTagType, P: Default = DefaultTagType> {
     pub option_announcer_chars: Vec<char>,
     pub option_codes_case_sensitive: bool,
     pub multi_char_option_code_requires_double_announcer: bool,
+    /// When `true` (and [`multi_char_option_code_requires_double_announcer`](Parser::multi_char_option_code_requires_double_announcer)
+    /// is also `true`), a single-announced run of single-character option codes is clustered/expanded the way
+    /// POSIX getopts does: `-abc` is parsed as `-a -b -c`. Expansion stops at the first code in the cluster
+    /// that is declared to take a value; any remaining characters become that code's value (as in `-xk5`
+    /// expanding to `-x -k 5`), otherwise the value is sought from the next argument as usual.
+    ///
+    /// Default: `false` (bundling conflicts with multi-char single-announcer codes)
+    pub short_option_bundling_enabled: bool,
+    /// When `true`, a token beginning with an option announcer character is reclassified as a parameter
+    /// rather than an option if the character immediately following the announcer is a digit (or a `.`
+    /// followed by a digit) and no registered matcher declares an option code starting with a digit. This
+    /// lets numeric-heavy CLIs accept negative operands, e.g. `-5` or `-3.14`, without quoting them.
+    ///
+    /// This only affects the start of a fresh argument token; `--count=-5` style value text is unaffected and
+    /// continues to be governed by [`Matcher::option_has_value`](crate::Matcher::option_has_value) handling.
+    ///
+    /// Default: `false`
+    pub negative_numbers_can_be_params: bool,
+    /// When `true`, a value whose first character is the option announcer is accepted instead of producing
+    /// [`ErrorId::OptionValueCannotBeginWithOptionAnnouncer`](crate::ErrorId::OptionValueCannotBeginWithOptionAnnouncer),
+    /// provided the whitespace-delimited token it starts parses as a numeric literal (optional sign, digits,
+    /// optional decimal point and digits, optional exponent). This is clap's `AllowHyphenValues`/`MaybeNegNum`
+    /// heuristic: it lets `--threshold -5` and a bare `-3.14` parameter work without quoting, while a token
+    /// such as `-x`, which is not a number, is handled exactly as it is when this is `false`.
+    ///
+    /// Unlike [`negative_numbers_can_be_params`](Parser::negative_numbers_can_be_params), which only affects
+    /// the start of a fresh argument token, this also governs whether an option's value may begin with the
+    /// announcer.
+    ///
+    /// Default: `false`
+    pub allow_hyphen_values: bool,
     /// The array of character any of which can be used end an option code and announce its option value.
     ///
     /// If an option argument does not end with this character, then it is a switch/flag only and does not include a value.
@@ -78,6 +119,10 @@ pub struct Parser<O: Default = DefaultTagType, P: Default = DefaultTagType> {
 
     matchers: Matchers<O, P>,
     fallback_matcher: Matcher<O, P>,
+    /// Child parsers keyed by subcommand name. When a parameter's text matches one of these names, the
+    /// matched name is emitted as an [`Arg::Command`](crate::Arg::Command) and this child [`Parser`] takes
+    /// over matching for every argument that follows, with `option_count`/`param_count` reset to `0` for it.
+    subcommands: HashMap<String, Parser<O, P>>,
 }
 
 impl<O: Default, P: Default> Parser<O, P> {
@@ -87,6 +132,9 @@ impl<O: Default, P: Default> Parser<O, P> {
             option_announcer_chars: DEFAULT_OPTION_ANNOUNCER_CHARS.to_vec(),
             option_codes_case_sensitive: DEFAULT_OPTION_CODES_CASE_SENSITIVE,
             multi_char_option_code_requires_double_announcer: DEFAULT_MULTI_CHAR_OPTION_CODE_REQUIRES_DOUBLE_ANNOUNCER,
+            short_option_bundling_enabled: DEFAULT_SHORT_OPTION_BUNDLING_ENABLED,
+            negative_numbers_can_be_params: DEFAULT_NEGATIVE_NUMBERS_CAN_BE_PARAMS,
+            allow_hyphen_values: DEFAULT_ALLOW_HYPHEN_VALUES,
             option_value_announcer_chars: DEFAULT_OPTION_VALUE_ANNOUNCER_CHARS.to_vec(),
             option_values_case_sensitive: DEFAULT_OPTION_VALUES_CASE_SENSITIVE,
             option_values_can_start_with_option_announcer_char: DEFAULT_OPTION_VALUES_CAN_START_WITH_OPTION_ANNOUNCER_CHAR,
@@ -98,6 +146,7 @@ impl<O: Default, P: Default> Parser<O, P> {
 
             matchers: Matchers::new(),
             fallback_matcher: Matcher::new(String::from("")),
+            subcommands: HashMap::new(),
         }
     }
 }
@@ -126,11 +175,44 @@ impl<O: Default, P: Default> Parser<O, P> {
         self.matchers.clear();
     }
 
+    /// Registers a child [`Parser`] as the named subcommand. Once a parameter's text matches `name`, that
+    /// child takes over matching for the remainder of the command line.
+    pub fn add_subcommand(&mut self, name: String, parser: Parser<O, P>) {
+        self.subcommands.insert(name, parser);
+    }
+
+    pub fn remove_subcommand(&mut self, name: &str) {
+        self.subcommands.remove(name);
+    }
+
+    pub fn get_subcommand(&self, name: &str) -> Option<&Parser<O, P>> {
+        self.subcommands.get(name)
+    }
+
     pub fn parse(&self, line: &str) -> Result<Args<O, P>, String> {
+        let (args, mut errors) = self.parse_internal(line, false);
+        match errors.pop() {
+            Some(error) => Err(error),
+            None => Ok(args),
+        }
+    }
+
+    /// Like [`parse`](Parser::parse), but continues past a recoverable error instead of stopping at the
+    /// first one: it resynchronizes at the next unquoted whitespace boundary and keeps parsing. Returns
+    /// whatever [`Args`] could be recovered alongside every diagnostic collected along the way, so tooling
+    /// (e.g. shell completions) can report every malformed token in one pass.
+    pub fn parse_collecting_errors(&self, line: &str) -> (Args<O, P>, Vec<String>) {
+        self.parse_internal(line, true)
+    }
+
+    fn parse_internal(&self, line: &str, accumulate_errors: bool) -> (Args<O, P>, Vec<String>) {
         let mut args = Vec::new();
+        let mut errors = Vec::new();
+        let mut current: &Parser<O, P> = self;
 
         let mut parse_state = ParseState {
             multi_char_option_code_requires_double_announcer: self.multi_char_option_code_requires_double_announcer,
+            short_option_bundling_enabled: self.short_option_bundling_enabled,
             line_len: line.chars().count(),
             arg_parse_state: ArgParseState::NotInArg,
             option_parse_state: OptionParseState::Announced,
@@ -146,84 +228,160 @@ impl<O: Default, P: Default> Parser<O, P> {
             arg_count: 0,
             option_count: 0,
             param_count: 0,
+            count_action_arg_indices: HashMap::new(),
+            pending_subcommand_switch: None,
+            multi_value_option_arg_index: None,
+            multi_value_total: 0,
+            multi_value_min: 0,
+            multi_value_max: 0,
         };
 
+        let chars: Vec<char> = line.chars().collect();
         let mut char_idx = 0;
-        for char in line.chars() {
-            let more = self.process_char(& mut parse_state, line, char_idx, char, &mut args)?;
+        while char_idx < chars.len() {
+            match current.process_char(&mut parse_state, line, char_idx, chars[char_idx], &mut args) {
+                Ok(more) => {
+                    current = Self::resolve_subcommand_switch(current, &mut parse_state);
+                    if !more {
+                        // ignore rest of line
+                        break;
+                    }
+                    char_idx += 1;
+                }
+                Err(error) => {
+                    errors.push(error);
+                    if !accumulate_errors {
+                        return (args, errors);
+                    }
+                    parse_state.arg_parse_state = ArgParseState::NotInArg;
+                    parse_state.value_quoted = false;
+                    parse_state.value_bldr.clear();
+                    if chars[char_idx].is_whitespace() {
+                        // The malformed token has already been fully read and the error was raised while
+                        // processing its terminating whitespace (e.g. an unmatched option code); that
+                        // whitespace hasn't been consumed yet, so stepping over it alone resumes parsing
+                        // at the very next token instead of sweeping past it.
+                        char_idx += 1;
+                    } else {
+                        // The error was raised mid-token (e.g. an unterminated quote); skip the remainder
+                        // of this token, including any quoted whitespace in it, before resuming at the
+                        // next whitespace-delimited one.
+                        let mut in_quotes = false;
+                        while char_idx < chars.len() && (in_quotes || !chars[char_idx].is_whitespace()) {
+                            if chars[char_idx] == current.quote_char {
+                                in_quotes = !in_quotes;
+                            }
+                            char_idx += 1;
+                        }
+                    }
+                }
+            }
+        }
 
-            if more {
-                char_idx += 1;
-            } else {
-                // ignore rest of line
-                break;
+        match current.finish_tail(line, &mut parse_state, &mut args) {
+            Ok(()) => {
+                if let Err(error) = current.finish_pending_multi_value(&mut parse_state) {
+                    errors.push(error);
+                }
             }
+            Err(error) => errors.push(error),
         }
 
-        match parse_state.arg_parse_state {
-            ArgParseState::NotInArg => {
+        (args, errors)
+    }
 
-            }
+    fn finish_tail<'a>(&'a self, line: &str, parse_state: &mut ParseState, args: &mut Args<'a, O, P>) -> Result<(), String> {
+        match parse_state.arg_parse_state {
+            ArgParseState::NotInArg => Ok(()),
 
             ArgParseState::InParam => {
                 if parse_state.value_quoted {
-                    self.create_error(Error::ParamMissingClosingQuoteCharacter, None)?;
+                    self.create_error(Error::ParamMissingClosingQuoteCharacter, None)
                 } else {
-                    self.match_param_arg(&mut parse_state, &mut args)?;
+                    self.match_param_arg(parse_state, args)
                 }
             }
 
-            ArgParseState::InParamPossibleEndQuote => {
-                self.match_param_arg(&mut parse_state, &mut args)?;
-            }
+            ArgParseState::InParamPossibleEndQuote => self.match_param_arg(parse_state, args),
 
-            ArgParseState::InParamEscaped => {
-                self.create_error(Error::InvalidEscapedCharacterInParam, Some(&parse_state.option_code))?;
-            }
+            ArgParseState::InParamEscaped => self.create_error(Error::InvalidEscapedCharacterInParam, Some(&parse_state.option_code)),
 
             ArgParseState::InOption => {
                 match parse_state.option_parse_state {
                     OptionParseState::Announced => {
-                        self.create_error(Error::NoCodeAfterOptionAnnouncer, Some(&parse_state.line_len.to_string()))?;
+                        self.create_error(Error::NoCodeAfterOptionAnnouncer, Some(&parse_state.line_len.to_string()))
                     }
                     OptionParseState::InCode => {
                         parse_state.set_option_code(line, None)?;
-                        self.match_option_arg(&mut parse_state, false, &mut args)?;
+                        if self.is_short_option_bundle_candidate(parse_state, line) {
+                            if self.match_bundled_option_codes(parse_state, args)? {
+                                // End of line reached with the trailing bundled code still waiting for a
+                                // value (e.g. "-xvf" with nothing left to supply it); resolve exactly like
+                                // a normal end-of-line `WaitOptionValue`.
+                                let has_value = self.can_option_have_value_with_first_char(parse_state, line, parse_state.line_len, false)?;
+                                match has_value {
+                                    OptionHasValueBasedOnFirstChar::Must => {
+                                        self.create_error(Error::NoMatchSupportsValueForOptionCode, Some(&parse_state.option_code))
+                                    }
+                                    OptionHasValueBasedOnFirstChar::Possibly | OptionHasValueBasedOnFirstChar::MustNot => {
+                                        parse_state.current_option_value_may_be_param = false;
+                                        self.match_option_arg(parse_state, false, args)
+                                    }
+                                }
+                            } else {
+                                Ok(())
+                            }
+                        } else {
+                            self.match_option_arg(parse_state, false, args)
+                        }
                     }
                     OptionParseState::WaitOptionValue => {
-                        let has_value = self.can_option_have_value_with_first_char(&parse_state, false)?;
+                        let has_value = self.can_option_have_value_with_first_char(parse_state, line, parse_state.line_len, false)?;
                         match has_value {
                             OptionHasValueBasedOnFirstChar::Must => {
-                                self.create_error(Error::NoMatchSupportsValueForOptionCode, Some(&parse_state.option_code))?;
-                            }
-                            OptionHasValueBasedOnFirstChar::Possibly => {
-                                parse_state.current_option_value_may_be_param = false;
-                                self.match_option_arg(&mut parse_state, false, &mut args)?;
+                                self.create_error(Error::NoMatchSupportsValueForOptionCode, Some(&parse_state.option_code))
                             }
-                            OptionHasValueBasedOnFirstChar::MustNot => {
+                            OptionHasValueBasedOnFirstChar::Possibly | OptionHasValueBasedOnFirstChar::MustNot => {
                                 parse_state.current_option_value_may_be_param = false;
-                                self.match_option_arg(&mut parse_state, false, &mut args)?;
+                                self.match_option_arg(parse_state, false, args)
                             }
                         }
                     }
                     OptionParseState::InValue => {
                         if parse_state.value_quoted {
-                            self.create_error(Error::OptionValueMissingClosingQuoteCharacter, Some(&parse_state.option_code))?;
+                            self.create_error(Error::OptionValueMissingClosingQuoteCharacter, Some(&parse_state.option_code))
                         } else {
-                            self.match_option_arg(&mut parse_state, true, &mut args)?;
+                            self.match_option_arg(parse_state, true, args)
                         }
                     }
-                    OptionParseState::InValuePossibleEndQuote => {
-                        self.match_option_arg(&mut parse_state, true, &mut args)?;
-                    }
+                    OptionParseState::InValuePossibleEndQuote => self.match_option_arg(parse_state, true, args),
                     OptionParseState::InValueEscaped => {
-                        self.create_error(Error::InvalidEscapedCharacterInOptionValue, Some(&parse_state.option_code))?;
+                        self.create_error(Error::InvalidEscapedCharacterInOptionValue, Some(&parse_state.option_code))
                     }
                 }
             }
         }
+    }
 
-        Ok(args)
+    /// If the argument just processed matched a registered subcommand name, switch matching over to that
+    /// child [`Parser`] and reset the option/param occurrence counters that are scoped to it.
+    fn resolve_subcommand_switch<'a>(current: &'a Parser<O, P>, parse_state: &mut ParseState) -> &'a Parser<O, P> {
+        match parse_state.pending_subcommand_switch.take() {
+            Some(subcommand_name) => {
+                match current.subcommands.get(&subcommand_name) {
+                    Some(subcommand_parser) => {
+                        parse_state.option_count = 0;
+                        parse_state.param_count = 0;
+                        // A `Count` matcher in the child parser must accumulate into its own `Arg::Option`
+                        // entry rather than being merged into a same-coded parent matcher's existing one.
+                        parse_state.count_action_arg_indices.clear();
+                        subcommand_parser
+                    }
+                    None => current,
+                }
+            }
+            None => current,
+        }
     }
 
     fn process_char<'a>(&'a self, parse_state: &mut ParseState, line: &str, char_idx: usize, line_char: char, args: &mut Args<'a, O, P>) -> Result<bool, String> {
@@ -238,7 +396,17 @@ impl<O: Default, P: Default> Parser<O, P> {
                     parse_state.value_bldr.clear();
                     parse_state.value_quoted = true;
                 } else {
-                    if self.option_announcer_chars.contains(&line_char) {
+                    let is_option_announcer = self.option_announcer_chars.contains(&line_char);
+                    if is_option_announcer && self.is_negative_number_param(line, char_idx) {
+                        // Reclassified as a negative-number parameter: fall through as ordinary param text.
+                        parse_state.arg_parse_state = ArgParseState::InParam;
+                        parse_state.arg_line_char_idx = char_idx;
+                        parse_state.start_idx = char_idx;
+                        parse_state.value_bldr.clear();
+                        parse_state.value_bldr.push(line_char);
+                        parse_state.value_quoted = false;
+                    } else if is_option_announcer {
+                        self.finish_pending_multi_value(parse_state)?;
                         parse_state.arg_parse_state = ArgParseState::InOption;
                         parse_state.option_parse_state = OptionParseState::Announced;
                         parse_state.option_announcer_char = line_char;
@@ -321,6 +489,11 @@ impl<O: Default, P: Default> Parser<O, P> {
                             parse_state.set_option_code(line, Some(char_idx))?;
                             if parse_state.option_code.is_empty() {
                                 self.create_error(Error::NoCodeAfterOptionAnnouncer, Some(&char_idx.to_string()))?;
+                            } else if self.is_short_option_bundle_candidate(parse_state, line) {
+                                let awaiting_value = self.match_bundled_option_codes(parse_state, args)?;
+                                if !awaiting_value {
+                                    parse_state.arg_parse_state = ArgParseState::NotInArg;
+                                }
                             } else {
                                 if option_value_announced {
                                     parse_state.option_value_announcer_is_ambiguous = line_char_is_whitespace;
@@ -345,8 +518,8 @@ impl<O: Default, P: Default> Parser<O, P> {
                     }
                     OptionParseState::WaitOptionValue => {
                         if !line_char.is_whitespace() {
-                            let first_char_of_value_is_option_announcer = self.option_value_announcer_chars.contains(&line_char);
-                            let has_value = self.can_option_have_value_with_first_char(parse_state, first_char_of_value_is_option_announcer)?;
+                            let first_char_of_value_is_option_announcer = self.option_announcer_chars.contains(&line_char);
+                            let has_value = self.can_option_have_value_with_first_char(parse_state, line, char_idx, first_char_of_value_is_option_announcer)?;
                             more = match has_value {
                                 OptionHasValueBasedOnFirstChar::Must => {
                                     parse_state.current_option_value_may_be_param = false;
@@ -444,21 +617,29 @@ impl<O: Default, P: Default> Parser<O, P> {
 
     fn can_option_code_have_value_with_matcher(&self, parse_state: &ParseState, matcher: &Matcher<O, P>) -> bool {
         if self.try_match_option_excluding_value(parse_state, matcher) {
-            let option_has_value = matcher.option_has_value.as_ref().unwrap_or(&DEFAULT_OPTION_HAS_VALUE);
-            *option_has_value != OptionHasValue::Never
-        } else { 
+            self.effective_option_has_value(matcher) != OptionHasValue::Never
+        } else {
             false
         }
     }
 
-    fn can_option_have_value_with_first_char(&self, parse_state: &ParseState, first_char_of_value_is_option_announcer: bool) -> Result<OptionHasValueBasedOnFirstChar, String> {
+    /// A `Flag`, `Count`, `SetTrue` or `SetFalse` action never consumes a value, regardless of what
+    /// [`Matcher::option_has_value`](Matcher::option_has_value) says.
+    fn effective_option_has_value(&self, matcher: &Matcher<O, P>) -> OptionHasValue {
+        match matcher.action.unwrap_or(DEFAULT_ACTION) {
+            Action::Flag | Action::Count | Action::SetTrue | Action::SetFalse => OptionHasValue::Never,
+            Action::Store => *matcher.option_has_value.as_ref().unwrap_or(&DEFAULT_OPTION_HAS_VALUE),
+        }
+    }
+
+    fn can_option_have_value_with_first_char(&self, parse_state: &ParseState, line: &str, char_idx: usize, first_char_of_value_is_option_announcer: bool) -> Result<OptionHasValueBasedOnFirstChar, String> {
         let mut has_value: OptionHasValueBasedOnFirstChar;
         if self.matchers.is_empty() {
-            self.can_option_have_value_with_first_char_with_matcher(parse_state, first_char_of_value_is_option_announcer, &self.fallback_matcher)
+            self.can_option_have_value_with_first_char_with_matcher(parse_state, line, char_idx, first_char_of_value_is_option_announcer, &self.fallback_matcher)
         } else {
             has_value = OptionHasValueBasedOnFirstChar::MustNot;
             for matcher in &self.matchers {
-                let matched_has_value = self.can_option_have_value_with_first_char_with_matcher(parse_state, first_char_of_value_is_option_announcer, matcher)?;
+                let matched_has_value = self.can_option_have_value_with_first_char_with_matcher(parse_state, line, char_idx, first_char_of_value_is_option_announcer, matcher)?;
                 match matched_has_value {
                     OptionHasValueBasedOnFirstChar::Must => return Ok(OptionHasValueBasedOnFirstChar::Must),
                     OptionHasValueBasedOnFirstChar::Possibly => has_value = OptionHasValueBasedOnFirstChar::Possibly,
@@ -470,14 +651,20 @@ impl<O: Default, P: Default> Parser<O, P> {
     }
 
     fn can_option_have_value_with_first_char_with_matcher(&self, parse_state: &ParseState,
+        line: &str, char_idx: usize,
         first_char_of_value_is_option_announcer: bool,
         matcher: &Matcher<O, P>
     ) -> Result<OptionHasValueBasedOnFirstChar, String> {
         if self.try_match_option_excluding_value(parse_state, matcher) {
-            let option_has_value = matcher.option_has_value.as_ref().unwrap_or(&DEFAULT_OPTION_HAS_VALUE);
-            match *option_has_value {
+            let option_has_value = self.effective_option_has_value(matcher);
+            // A value that looks like a negative number is admitted in place of an `Err`/`MustNot` verdict
+            // when `allow_hyphen_values` is enabled, per clap's `AllowHyphenValues`/`MaybeNegNum` heuristic.
+            let value_looks_like_negative_number = first_char_of_value_is_option_announcer
+                && self.value_looks_like_negative_number(line, char_idx);
+
+            match option_has_value {
                 OptionHasValue::AlwaysButValueMustNotStartWithOptionAnnouncer => {
-                    if first_char_of_value_is_option_announcer {
+                    if first_char_of_value_is_option_announcer && !value_looks_like_negative_number {
                         Err(Error::OptionValueCannotBeginWithOptionAnnouncer.to_text(Some(&parse_state.option_code)))
                     } else {
                         Ok(OptionHasValueBasedOnFirstChar::Must)
@@ -488,13 +675,13 @@ impl<O: Default, P: Default> Parser<O, P> {
                 }
                 OptionHasValue::IfPossible => {
                     if parse_state.option_value_announcer_is_ambiguous {
-                        if first_char_of_value_is_option_announcer {
+                        if first_char_of_value_is_option_announcer && !value_looks_like_negative_number {
                             Ok(OptionHasValueBasedOnFirstChar::MustNot)
                         } else {
                             Ok(OptionHasValueBasedOnFirstChar::Possibly)
                         }
                     } else {
-                        if first_char_of_value_is_option_announcer {
+                        if first_char_of_value_is_option_announcer && !value_looks_like_negative_number {
                             Err(Error::OptionValueCannotBeginWithOptionAnnouncer.to_text(Some(&parse_state.option_code)))
                         } else {
                             Ok(OptionHasValueBasedOnFirstChar::Must)
@@ -502,7 +689,9 @@ impl<O: Default, P: Default> Parser<O, P> {
                     }
                 }
                 OptionHasValue::Never => {
-                    unreachable!("Unexpected never branch in function: \"{}\", module: \"{}\"", "", module_path!());
+                    // A Flag/Count/SetTrue/SetFalse action, or another matcher for the same code that never
+                    // takes a value, can reach here alongside matchers that do want a value.
+                    Ok(OptionHasValueBasedOnFirstChar::MustNot)
                 }
             }
         } else {
@@ -563,6 +752,9 @@ impl<O: Default, P: Default> Parser<O, P> {
     fn match_option_arg<'a>(&'a self, parse_state: &mut ParseState, has_value: bool, args: &mut Args<'a, O, P>) -> Result<(), String> {
         let mut optioned_matcher = self.try_find_option_matcher(parse_state, has_value);
         if let Some(matcher) = optioned_matcher {
+            if has_value {
+                self.check_value_possible_values(&parse_state.value_bldr, matcher)?;
+            }
             self.add_option_arg(parse_state, has_value, matcher, args);
             Ok(())
         } else {
@@ -575,19 +767,32 @@ impl<O: Default, P: Default> Parser<O, P> {
                     self.match_param_arg(parse_state, args)?;
                     Ok(())
                 } else {
-                    self.create_error(Error::UnmatchedOption, Some(&parse_state.option_code))
+                    self.create_unmatched_option_error(parse_state)
                 }
             } else {
-                self.create_error(Error::UnmatchedOption, Some(&parse_state.option_code))
+                self.create_unmatched_option_error(parse_state)
             }
         }
     }
 
     fn add_option_arg<'a>(&self, parse_state: &mut ParseState, has_value: bool, matcher: &'a Matcher<O, P>, args: &mut Args<'a, O, P>) {
+        let action = matcher.action.unwrap_or(DEFAULT_ACTION);
+
+        if action == Action::Count {
+            if let Some(&existing_arg_index) = parse_state.count_action_arg_indices.get(&parse_state.option_code) {
+                if let Arg::Option(existing_properties) = &mut args[existing_arg_index] {
+                    existing_properties.count += 1;
+                }
+                parse_state.arg_count += 1;
+                parse_state.option_count += 1;
+                return;
+            }
+        }
+
         let value_text = if has_value {
-            Some(parse_state.value_bldr.clone())
+            vec![parse_state.value_bldr.clone()]
         } else {
-            None
+            Vec::new()
         };
         let properties = OptionProperties {
             matcher,
@@ -595,14 +800,184 @@ impl<O: Default, P: Default> Parser<O, P> {
             arg_index: parse_state.arg_count,
             option_index: parse_state.option_count,
             code: parse_state.option_code.clone(),
-            value_text
+            value_text,
+            count: 1,
         };
 
+        if action == Action::Count {
+            parse_state.count_action_arg_indices.insert(parse_state.option_code.clone(), args.len());
+        }
+
+        let option_arg_index = args.len();
         let arg = Arg::Option(properties);
         args.push(arg);
 
         parse_state.arg_count += 1;
         parse_state.option_count += 1;
+
+        if has_value {
+            self.begin_multi_value_gather(parse_state, matcher, option_arg_index);
+        }
+    }
+
+    /// Arms value-gathering when `matcher` allows more than one value (see
+    /// [`Matcher::value_count_max`](Matcher::value_count_max)), so that subsequent whitespace-separated
+    /// params are folded into this option's `value_text` instead of being matched as independent arguments.
+    fn begin_multi_value_gather(&self, parse_state: &mut ParseState, matcher: &Matcher<O, P>, option_arg_index: usize) {
+        let max = Self::effective_value_count_max(matcher);
+        if max > 1 {
+            parse_state.multi_value_option_arg_index = Some(option_arg_index);
+            parse_state.multi_value_total = 1;
+            parse_state.multi_value_min = Self::effective_value_count_min(matcher);
+            parse_state.multi_value_max = max;
+        }
+    }
+
+    fn effective_value_count_max(matcher: &Matcher<O, P>) -> usize {
+        match (matcher.value_count_min, matcher.value_count_max) {
+            (None, None) => 1,
+            (_, Some(max)) => max,
+            (Some(_), None) => usize::MAX,
+        }
+    }
+
+    fn effective_value_count_min(matcher: &Matcher<O, P>) -> usize {
+        matcher.value_count_min.unwrap_or(1)
+    }
+
+    /// Folds the just-parsed param text into the value list of the option currently gathering multiple
+    /// values (see [`begin_multi_value_gather`](Parser::begin_multi_value_gather)), stopping the gather once
+    /// [`Matcher::value_count_max`](Matcher::value_count_max) is reached.
+    fn add_multi_value<'a>(&self, parse_state: &mut ParseState, option_arg_index: usize, args: &mut Args<'a, O, P>) -> Result<(), String> {
+        if let Arg::Option(properties) = &args[option_arg_index] {
+            self.check_value_possible_values(&parse_state.value_bldr, properties.matcher)?;
+        }
+
+        if let Arg::Option(properties) = &mut args[option_arg_index] {
+            properties.value_text.push(parse_state.value_bldr.clone());
+        }
+
+        parse_state.multi_value_total += 1;
+
+        if parse_state.multi_value_total >= parse_state.multi_value_max {
+            parse_state.multi_value_option_arg_index = None;
+        }
+
+        Ok(())
+    }
+
+    /// Validates that an in-progress multi-value gather met [`Matcher::value_count_min`](Matcher::value_count_min)
+    /// before it is abandoned, either because a new option was announced or the command line ended.
+    fn finish_pending_multi_value(&self, parse_state: &mut ParseState) -> Result<(), String> {
+        if parse_state.multi_value_option_arg_index.take().is_some() && parse_state.multi_value_total < parse_state.multi_value_min {
+            self.create_error(Error::InsufficientOptionValues, Some(&format!(
+                "expected at least {} value(s) but found {}", parse_state.multi_value_min, parse_state.multi_value_total
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn is_short_option_bundle_candidate(&self, parse_state: &ParseState, line: &str) -> bool {
+        self.short_option_bundling_enabled
+        &&
+        self.multi_char_option_code_requires_double_announcer
+        &&
+        parse_state.option_code.chars().count() > 1
+        &&
+        line.chars().nth(parse_state.start_idx) != Some(parse_state.option_announcer_char)
+    }
+
+    /// Expands a clustered single-announced run of single-character option codes (e.g. `abc` from `-abc`)
+    /// into its constituent options, each matched and added independently. Stops clustering at the first code
+    /// declared to take a value: a value concatenated onto the remainder of the token (`-xvfarchive`) is
+    /// assigned immediately, but if nothing remains (`-xvf`), returns `true` so the caller can leave the
+    /// option in a `WaitOptionValue` state and pick the value up from the next whitespace-delimited token
+    /// (`tar -xvf archive` getopts semantics), rather than matching it as a bare flag.
+    fn match_bundled_option_codes<'a>(&'a self, parse_state: &mut ParseState, args: &mut Args<'a, O, P>) -> Result<bool, String> {
+        let codes: Vec<char> = parse_state.option_code.chars().collect();
+
+        for (index, code_char) in codes.iter().enumerate() {
+            parse_state.option_code = code_char.to_string();
+
+            let has_value_matcher = self.try_find_option_matcher(parse_state, false);
+            match has_value_matcher {
+                None => {
+                    // Not every character is a registered option code, so this is not a bundle.
+                    let raw_option_code: String = codes.iter().collect();
+                    self.create_error(Error::OptionCodeMissingDoubleAnnouncer, Some(&raw_option_code))?;
+                }
+                Some(matcher) => {
+                    if self.effective_option_has_value(matcher) == OptionHasValue::Never {
+                        parse_state.current_option_value_may_be_param = false;
+                        self.match_option_arg(parse_state, false, args)?;
+                    } else {
+                        let remaining_value: String = codes[(index + 1)..].iter().collect();
+                        if remaining_value.is_empty() {
+                            parse_state.option_parse_state = OptionParseState::WaitOptionValue;
+                            parse_state.option_value_announcer_is_ambiguous = true;
+                            return Ok(true);
+                        } else {
+                            parse_state.value_bldr = remaining_value;
+                            parse_state.value_quoted = false;
+                            parse_state.current_option_value_may_be_param = false;
+                            self.match_option_arg(parse_state, true, args)?;
+                        }
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Returns `true` when the option announcer character at `char_idx` should instead be treated as the
+    /// start of a negative-number parameter, per [`negative_numbers_can_be_params`](Parser::negative_numbers_can_be_params)
+    /// or [`allow_hyphen_values`](Parser::allow_hyphen_values).
+    fn is_negative_number_param(&self, line: &str, char_idx: usize) -> bool {
+        if self.any_matcher_option_code_starts_with_digit() {
+            return false;
+        }
+
+        if self.negative_numbers_can_be_params && Self::digit_follows_announcer(line, char_idx) {
+            return true;
+        }
+
+        self.value_looks_like_negative_number(line, char_idx)
+    }
+
+    /// Returns `true` if the character immediately after the announcer at `char_idx` is a digit, or a `.`
+    /// followed by a digit — the lighter-weight heuristic used by
+    /// [`negative_numbers_can_be_params`](Parser::negative_numbers_can_be_params).
+    fn digit_follows_announcer(line: &str, char_idx: usize) -> bool {
+        let mut chars_after_announcer = line.chars().skip(char_idx + 1);
+        match chars_after_announcer.next() {
+            Some(next_char) if next_char.is_ascii_digit() => true,
+            Some('.') => matches!(chars_after_announcer.next(), Some(following_char) if following_char.is_ascii_digit()),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` when [`allow_hyphen_values`](Parser::allow_hyphen_values) is enabled and the
+    /// whitespace-delimited token starting at `char_idx` (expected to begin with the option announcer
+    /// character) parses as a numeric literal.
+    fn value_looks_like_negative_number(&self, line: &str, char_idx: usize) -> bool {
+        self.allow_hyphen_values && looks_like_numeric_literal(line, char_idx)
+    }
+
+    /// Returns `true` if any registered matcher declares a literal option code beginning with a digit, in
+    /// which case a leading-digit token after the announcer is left classified as an option rather than being
+    /// reclassified as a negative-number parameter.
+    fn any_matcher_option_code_starts_with_digit(&self) -> bool {
+        self.matchers.iter().any(|matcher| {
+            matcher.option_codes.as_ref().is_some_and(|option_codes| {
+                option_codes.iter().any(|option_code| match option_code {
+                    RegexOrText::Text(text) => text.chars().next().is_some_and(|first_char| first_char.is_ascii_digit()),
+                    RegexOrText::Regex(_) => false,
+                })
+            })
+        })
     }
 
     fn try_find_option_matcher(&self, parse_state: &ParseState, has_value: bool) -> Option<&Matcher<O, P>> {
@@ -616,8 +991,7 @@ impl<O: Default, P: Default> Parser<O, P> {
     fn try_match_option(&self, parse_state: &ParseState, has_value: bool, matcher: &Matcher<O, P>) -> bool {
         if  self.try_match_option_excluding_value(parse_state, matcher) {
             // want to match value
-            let unwrapped_matcher_option_has_value = matcher.option_has_value.as_ref().unwrap_or(&DEFAULT_OPTION_HAS_VALUE);
-            match *unwrapped_matcher_option_has_value {
+            match self.effective_option_has_value(matcher) {
                 OptionHasValue::AlwaysAndValueCanStartWithOptionAnnouncer => {
                     // matcher expects value
                     if has_value {
@@ -665,6 +1039,17 @@ impl<O: Default, P: Default> Parser<O, P> {
     }
 
     fn match_param_arg<'a>(&'a self, parse_state: &mut ParseState, args: &mut Args<'a, O, P>) -> Result<(), String> {
+        if let Some(option_arg_index) = parse_state.multi_value_option_arg_index {
+            self.add_multi_value(parse_state, option_arg_index, args)?;
+            return Ok(());
+        }
+
+        if let Some(subcommand_name) = self.try_match_subcommand_name(&parse_state.value_bldr) {
+            self.add_command_arg(parse_state, subcommand_name.clone(), args);
+            parse_state.pending_subcommand_switch = Some(subcommand_name);
+            return Ok(());
+        }
+
         let optioned_matcher = if self.matchers.is_empty() {
             Some(&self.fallback_matcher)
         } else {
@@ -672,6 +1057,7 @@ impl<O: Default, P: Default> Parser<O, P> {
         };
 
         if let Some(matcher) = optioned_matcher {
+            self.check_value_possible_values(&parse_state.value_bldr, matcher)?;
             self.add_param_arg(parse_state, matcher, args);
             Ok(())
         } else {
@@ -679,6 +1065,32 @@ impl<O: Default, P: Default> Parser<O, P> {
         }
     }
 
+    fn try_match_subcommand_name(&self, text: &str) -> Option<String> {
+        if self.subcommands.is_empty() {
+            return None;
+        }
+
+        self.subcommands.keys().find(|name| {
+            if self.params_case_sensitive {
+                name.as_str() == text
+            } else {
+                name.eq_ignore_ascii_case(text)
+            }
+        }).cloned()
+    }
+
+    fn add_command_arg<'a>(&'a self, parse_state: &mut ParseState, name: String, args: &mut Args<'a, O, P>) {
+        let properties = CommandProperties {
+            matcher: &self.fallback_matcher,
+            line_char_index: parse_state.arg_line_char_idx,
+            arg_index: parse_state.arg_count,
+            name,
+        };
+
+        args.push(Arg::Command(properties));
+        parse_state.arg_count += 1;
+    }
+
     fn add_param_arg<'a>(&self, parse_state: &mut ParseState, matcher: &'a Matcher<O, P>, args: &mut Args<'a, O, P>) {
         let properties = ParamProperties {
             matcher,
@@ -752,14 +1164,443 @@ impl<O: Default, P: Default> Parser<O, P> {
         }
     }
 
+    /// Checks `value_text` against [`Matcher::value_possible_values`](Matcher::value_possible_values), if the
+    /// matcher declares one. On failure, produces [`Error::UnmatchedValue`](Error::UnmatchedValue) listing the
+    /// allowed literals and, where the value is close to one of them, a "did you mean" suggestion.
+    fn check_value_possible_values(&self, value_text: &str, matcher: &Matcher<O, P>) -> Result<(), String> {
+        let Some(possible_values) = &matcher.value_possible_values else {
+            return Ok(());
+        };
+
+        let is_allowed = possible_values.iter().any(|possible_value| possible_value.is_match(value_text, self.option_values_case_sensitive));
+        if is_allowed {
+            return Ok(());
+        }
+
+        let allowed_literals: Vec<&str> = possible_values.iter().filter_map(|possible_value| match possible_value {
+            RegexOrText::Text(text) => Some(text.as_str()),
+            RegexOrText::Regex(_) => None,
+        }).collect();
+
+        let mut message = format!("\"{}\" is not one of the allowed values: {}", value_text, allowed_literals.join(", "));
+        if let Some(suggestion) = closest_allowed_value(value_text, &allowed_literals) {
+            message.push_str(&format!(" (did you mean \"{}\"?)", suggestion));
+        }
+
+        self.create_error(Error::UnmatchedValue, Some(&message))
+    }
+
+    /// Produces [`Error::UnmatchedOption`](Error::UnmatchedOption) for `parse_state.option_code`, including a
+    /// "did you mean" suggestion when a registered matcher declares a literal option code within edit
+    /// distance of it.
+    fn create_unmatched_option_error(&self, parse_state: &ParseState) -> Result<(), String> {
+        let mut message = parse_state.option_code.clone();
+
+        let candidate_codes: Vec<&str> = self.matchers.iter()
+            .filter_map(|matcher| matcher.option_codes.as_ref())
+            .flatten()
+            .filter_map(|option_code| match option_code {
+                RegexOrText::Text(text) => Some(text.as_str()),
+                RegexOrText::Regex(_) => None,
+            })
+            .collect();
+
+        if let Some(suggestion) = closest_matching_option_code(&parse_state.option_code, &candidate_codes, self.option_codes_case_sensitive) {
+            message.push_str(&format!(" (did you mean \"{}\"?)", suggestion));
+        }
+
+        self.create_error(Error::UnmatchedOption, Some(&message))
+    }
+
     fn create_error(&self, error_id: Error, extra: Option<&str>) -> Result<(), String> {
         let error_text = error_id.to_text(extra);
         Err(error_text)
     }
 }
 
+impl<O: Default + PartialEq, P: Default> Parser<O, P> {
+    /// Validates inter-argument constraints declared on matchers (clap-style `requires`/`conflicts_with`/
+    /// required-argument checks) against a fully-parsed [`Args`]. Intended to be called after
+    /// [`parse`](Parser::parse)/[`parse_collecting_errors`](Parser::parse_collecting_errors) succeeds.
+    ///
+    /// Checks, in order: (1) every matched matcher's [`requires`](Matcher::requires) tags are also present;
+    /// (2) no matched matcher lists another matched option's tag in [`conflicts_with`](Matcher::conflicts_with); (3) every
+    /// matcher with [`is_required`](Matcher::is_required) set has its [`option_tag`](Matcher::option_tag)
+    /// among the matched options. Returns the first violation found.
+    pub fn validate(&self, args: &Args<O, P>) -> Result<(), String> {
+        let matched_tags: Vec<&O> = args.iter().filter_map(|arg| match arg {
+            Arg::Option(properties) => properties.matcher.option_tag.as_ref(),
+            _ => None,
+        }).collect();
+
+        for arg in args {
+            if let Arg::Option(properties) = arg {
+                if let Some(requires) = &properties.matcher.requires {
+                    for required_tag in requires {
+                        if !matched_tags.contains(&required_tag) {
+                            return self.create_error(Error::RequiredDependencyMissing, Some(&properties.code));
+                        }
+                    }
+                }
+            }
+        }
+
+        for arg in args {
+            if let Arg::Option(properties) = arg {
+                if let Some(conflicts_with) = &properties.matcher.conflicts_with {
+                    for conflicting_tag in conflicts_with {
+                        if matched_tags.contains(&conflicting_tag) {
+                            return self.create_error(Error::ConflictingOptionsMatched, Some(&properties.code));
+                        }
+                    }
+                }
+            }
+        }
+
+        for matcher in &self.matchers {
+            if matcher.is_required {
+                let is_satisfied = matcher.option_tag.as_ref().is_some_and(|tag| matched_tags.contains(&tag));
+                if !is_satisfied {
+                    return self.create_error(Error::RequiredOptionMissing, Some(&matcher.name));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 enum OptionHasValueBasedOnFirstChar {
     Must,
     Possibly,
     MustNot,
-}
\ No newline at end of file
+}
+
+/// Returns the allowed literal closest to `value_text` by Levenshtein distance (insert/delete/substitute cost
+/// 1), provided that distance is within `max(2, value_text.len() / 3)`; otherwise `None`.
+fn closest_allowed_value<'a>(value_text: &str, allowed_literals: &[&'a str]) -> Option<&'a str> {
+    let threshold = std::cmp::max(2, value_text.chars().count() / 3);
+
+    allowed_literals.iter()
+        .map(|&literal| (literal, levenshtein_distance(value_text, literal)))
+        .filter(|&(_, distance)| distance <= threshold)
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(literal, _)| literal)
+}
+
+/// Returns the candidate option code closest to `option_code` by Levenshtein distance, honoring
+/// `case_sensitive`, provided that distance is within `max(2, option_code.len() / 3)`; otherwise `None`.
+fn closest_matching_option_code<'a>(option_code: &str, candidates: &[&'a str], case_sensitive: bool) -> Option<&'a str> {
+    let normalize = |text: &str| if case_sensitive { text.to_string() } else { text.to_lowercase() };
+    let normalized_option_code = normalize(option_code);
+    let threshold = std::cmp::max(2, normalized_option_code.chars().count() / 3);
+
+    candidates.iter()
+        .map(|&candidate| (candidate, levenshtein_distance(&normalized_option_code, &normalize(candidate))))
+        .filter(|&(_, distance)| distance <= threshold)
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Returns `true` if the whitespace-delimited token starting at `char_idx` parses as a numeric literal
+/// (optional sign, digits, optional decimal point and digits, optional exponent) — clap's `MaybeNegNum`
+/// heuristic, used by [`Parser::allow_hyphen_values`](Parser::allow_hyphen_values) to admit negative numbers
+/// as option values or parameters instead of erroring or being misread as a new option.
+fn looks_like_numeric_literal(line: &str, char_idx: usize) -> bool {
+    let token: String = line.chars().skip(char_idx).take_while(|c| !c.is_whitespace()).collect();
+    // `f64` also parses `inf`/`infinity`/`nan`, none of which contain a digit.
+    token.parse::<f64>().is_ok() && token.chars().any(|c| c.is_ascii_digit())
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (a_len, b_len) = (a_chars.len(), b_chars.len());
+
+    let mut previous_row: Vec<usize> = (0..=b_len).collect();
+    let mut current_row = vec![0usize; b_len + 1];
+
+    for i in 1..=a_len {
+        current_row[0] = i;
+        for j in 1..=b_len {
+            let substitution_cost = if a_chars[i - 1] == b_chars[j - 1] { 0 } else { 1 };
+            current_row[j] = (previous_row[j] + 1)
+                .min(current_row[j - 1] + 1)
+                .min(previous_row[j - 1] + substitution_cost);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[b_len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matcher::i64_value_parser;
+
+    #[test]
+    fn option_value_parses_via_configured_value_parser() {
+        let mut parser: Parser = Parser::new();
+        let mut matcher = Matcher::new(String::from("count"));
+        matcher.option_codes = Some(vec![RegexOrText::Text(String::from("count"))]);
+        matcher.value_parser = Some(i64_value_parser());
+        parser.add_matcher(matcher);
+
+        let args = parser.parse("-count 42").expect("should parse");
+        assert_eq!(args.len(), 1);
+        match &args[0] {
+            Arg::Option(properties) => assert_eq!(properties.get_value::<i64>().unwrap(), 42),
+            _ => panic!("expected an option arg"),
+        }
+    }
+
+    #[test]
+    fn count_action_collapses_repeated_occurrences() {
+        let mut parser: Parser = Parser::new();
+        let mut matcher = Matcher::new(String::from("verbose"));
+        matcher.option_codes = Some(vec![RegexOrText::Text(String::from("v"))]);
+        matcher.action = Some(Action::Count);
+        parser.add_matcher(matcher);
+
+        let args = parser.parse("-v -v -v").expect("should parse");
+        assert_eq!(args.len(), 1);
+        match &args[0] {
+            Arg::Option(properties) => assert_eq!(properties.count, 3),
+            _ => panic!("expected a single collapsed option arg"),
+        }
+    }
+
+    #[test]
+    fn short_option_bundling_expands_clustered_codes() {
+        let mut parser: Parser = Parser::new();
+        parser.multi_char_option_code_requires_double_announcer = true;
+        parser.short_option_bundling_enabled = true;
+
+        for code in ["x", "v", "f"] {
+            let mut matcher = Matcher::new(code.to_string());
+            matcher.option_codes = Some(vec![RegexOrText::Text(code.to_string())]);
+            matcher.action = Some(Action::Flag);
+            parser.add_matcher(matcher);
+        }
+
+        let args = parser.parse("-xvf").expect("should parse");
+        let codes: Vec<&str> = args.iter().map(|arg| match arg {
+            Arg::Option(properties) => properties.code.as_str(),
+            _ => panic!("expected only option args"),
+        }).collect();
+        assert_eq!(codes, vec!["x", "v", "f"]);
+    }
+
+    #[test]
+    fn short_option_bundling_gives_trailing_value_taking_code_the_next_token() {
+        let mut parser: Parser = Parser::new();
+        parser.multi_char_option_code_requires_double_announcer = true;
+        parser.short_option_bundling_enabled = true;
+
+        for code in ["x", "v"] {
+            let mut matcher = Matcher::new(code.to_string());
+            matcher.option_codes = Some(vec![RegexOrText::Text(code.to_string())]);
+            matcher.action = Some(Action::Flag);
+            parser.add_matcher(matcher);
+        }
+        let mut f_matcher = Matcher::new(String::from("f"));
+        f_matcher.option_codes = Some(vec![RegexOrText::Text(String::from("f"))]);
+        parser.add_matcher(f_matcher);
+
+        let args = parser.parse("-xvf archive").expect("should parse");
+        assert_eq!(args.len(), 3);
+        match &args[2] {
+            Arg::Option(properties) => {
+                assert_eq!(properties.code, "f");
+                assert_eq!(properties.value_text, vec![String::from("archive")]);
+            }
+            _ => panic!("expected f's value to be the following whitespace-delimited token"),
+        }
+    }
+
+    #[test]
+    fn subcommand_dispatch_keeps_parent_and_child_count_actions_independent() {
+        let mut parser: Parser = Parser::new();
+        let mut parent_v = Matcher::new(String::from("v"));
+        parent_v.option_codes = Some(vec![RegexOrText::Text(String::from("v"))]);
+        parent_v.action = Some(Action::Count);
+        parser.add_matcher(parent_v);
+
+        let mut child: Parser = Parser::new();
+        let mut child_v = Matcher::new(String::from("v"));
+        child_v.option_codes = Some(vec![RegexOrText::Text(String::from("v"))]);
+        child_v.action = Some(Action::Count);
+        child.add_matcher(child_v);
+        parser.add_subcommand(String::from("build"), child);
+
+        let args = parser.parse("-v build -v").expect("should parse");
+        assert_eq!(args.len(), 3);
+        let option_counts: Vec<usize> = args.iter().filter_map(|arg| match arg {
+            Arg::Option(properties) => Some(properties.count),
+            _ => None,
+        }).collect();
+        assert_eq!(option_counts, vec![1, 1]);
+    }
+
+    #[test]
+    fn parse_collecting_errors_continues_past_errors() {
+        let mut parser: Parser = Parser::new();
+        let mut matcher = Matcher::new(String::from("a"));
+        matcher.option_codes = Some(vec![RegexOrText::Text(String::from("a"))]);
+        matcher.option_or_parameter = Some(OptionOrParameter::Option);
+        matcher.action = Some(Action::Flag);
+        parser.add_matcher(matcher);
+
+        let (args, errors) = parser.parse_collecting_errors("-b ok -a");
+        assert_eq!(errors.len(), 2);
+        assert_eq!(args.len(), 1);
+        match &args[0] {
+            Arg::Option(properties) => assert_eq!(properties.code, "a"),
+            _ => panic!("expected the matched option"),
+        }
+    }
+
+    #[test]
+    fn negative_numbers_can_be_params_reclassifies_leading_dash_numbers() {
+        let mut parser: Parser = Parser::new();
+        parser.negative_numbers_can_be_params = true;
+
+        let args = parser.parse("-5").expect("should parse");
+        assert_eq!(args.len(), 1);
+        match &args[0] {
+            Arg::Param(properties) => assert_eq!(properties.value_text, "-5"),
+            _ => panic!("expected a param, not an option"),
+        }
+    }
+
+    #[test]
+    fn value_possible_values_rejects_unlisted_value_with_suggestion() {
+        let mut parser: Parser = Parser::new();
+        let mut matcher = Matcher::new(String::from("color"));
+        matcher.option_or_parameter = Some(OptionOrParameter::Parameter);
+        matcher.value_possible_values = Some(vec![
+            RegexOrText::Text(String::from("red")),
+            RegexOrText::Text(String::from("green")),
+            RegexOrText::Text(String::from("blue")),
+        ]);
+        parser.add_matcher(matcher);
+
+        let args = parser.parse("green").expect("allowed value should parse");
+        assert_eq!(args.len(), 1);
+
+        let error = match parser.parse("gren") {
+            Err(error) => error,
+            Ok(_) => panic!("unlisted value should error"),
+        };
+        assert!(error.contains("did you mean \"green\"?"), "unexpected error: {error}");
+    }
+
+    #[test]
+    fn multi_value_option_gathers_values_up_to_max_and_enforces_min() {
+        let make_parser = || {
+            let mut parser: Parser = Parser::new();
+            let mut matcher = Matcher::new(String::from("coords"));
+            matcher.option_codes = Some(vec![RegexOrText::Text(String::from("coords"))]);
+            matcher.value_count_min = Some(2);
+            matcher.value_count_max = Some(3);
+            parser.add_matcher(matcher);
+            parser
+        };
+
+        let parser = make_parser();
+        let args = parser.parse("-coords 1 2 3").expect("should parse");
+        assert_eq!(args.len(), 1);
+        match &args[0] {
+            Arg::Option(properties) => assert_eq!(properties.value_text, vec!["1", "2", "3"]),
+            _ => panic!("expected an option arg"),
+        }
+
+        let below_minimum_parser = make_parser();
+        let error = match below_minimum_parser.parse("-coords 1") {
+            Err(error) => error,
+            Ok(_) => panic!("below minimum should error"),
+        };
+        assert!(error.to_lowercase().contains("enough values"), "unexpected error: {error}");
+    }
+
+    #[test]
+    fn validate_checks_requires_and_conflicts() {
+        #[derive(Default, PartialEq, Eq, Clone, Copy)]
+        enum OptTag {
+            #[default]
+            None,
+            A,
+            B,
+        }
+
+        let mut parser: Parser<OptTag> = Parser::new();
+        let mut matcher_a = Matcher::new(String::from("a"));
+        matcher_a.option_codes = Some(vec![RegexOrText::Text(String::from("a"))]);
+        matcher_a.action = Some(Action::Flag);
+        matcher_a.option_tag = Some(OptTag::A);
+        matcher_a.requires = Some(vec![OptTag::B]);
+        parser.add_matcher(matcher_a);
+
+        let mut matcher_b = Matcher::new(String::from("b"));
+        matcher_b.option_codes = Some(vec![RegexOrText::Text(String::from("b"))]);
+        matcher_b.action = Some(Action::Flag);
+        matcher_b.option_tag = Some(OptTag::B);
+        parser.add_matcher(matcher_b);
+
+        let args_missing_requirement = parser.parse("-a").expect("should parse");
+        assert!(parser.validate(&args_missing_requirement).is_err());
+
+        let args_satisfied = parser.parse("-a -b").expect("should parse");
+        assert!(parser.validate(&args_satisfied).is_ok());
+    }
+
+    #[test]
+    fn value_as_parses_via_from_str_without_a_configured_value_parser() {
+        let mut parser: Parser = Parser::new();
+        let mut matcher = Matcher::new(String::from("ports"));
+        matcher.option_codes = Some(vec![RegexOrText::Text(String::from("ports"))]);
+        matcher.value_count_max = Some(3);
+        parser.add_matcher(matcher);
+
+        let args = parser.parse("-ports 80 443 8080").expect("should parse");
+        assert_eq!(args.len(), 1);
+        match &args[0] {
+            Arg::Option(properties) => {
+                assert_eq!(properties.value_as::<u16>().unwrap(), 80);
+                assert_eq!(properties.values_as::<u16>().unwrap(), vec![80, 443, 8080]);
+            }
+            _ => panic!("expected an option arg"),
+        }
+    }
+
+    #[test]
+    fn unmatched_option_error_suggests_closest_option_code() {
+        let mut parser: Parser = Parser::new();
+        let mut matcher = Matcher::new(String::from("verbose"));
+        matcher.option_codes = Some(vec![RegexOrText::Text(String::from("verbose"))]);
+        parser.add_matcher(matcher);
+
+        let error = match parser.parse("-verbos") {
+            Err(error) => error,
+            Ok(_) => panic!("unmatched option should error"),
+        };
+        assert!(error.contains("did you mean \"verbose\"?"), "unexpected error: {error}");
+    }
+
+    #[test]
+    fn allow_hyphen_values_lets_an_option_take_a_negative_number_value() {
+        let mut parser: Parser = Parser::new();
+        parser.allow_hyphen_values = true;
+        let mut matcher = Matcher::new(String::from("offset"));
+        matcher.option_codes = Some(vec![RegexOrText::Text(String::from("offset"))]);
+        parser.add_matcher(matcher);
+
+        let args = parser.parse("-offset -5").expect("should parse");
+        assert_eq!(args.len(), 1);
+        match &args[0] {
+            Arg::Option(properties) => assert_eq!(properties.value_text, vec![String::from("-5")]),
+            _ => panic!("expected an option arg with a negative-number value"),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use regex::Regex;
+
+/// A value used by a [`Matcher`](crate::Matcher) to decide whether an option code or a value matches.
+///
+/// Either a literal piece of text (compared according to a case sensitivity flag) or a regular expression
+/// (whose own case sensitivity is baked into the compiled [`Regex`]).
+pub enum RegexOrText {
+    Text(String),
+    Regex(Regex),
+}
+
+impl RegexOrText {
+    pub fn is_match(&self, value: &str, case_sensitive: bool) -> bool {
+        match self {
+            RegexOrText::Text(text) => {
+                if case_sensitive {
+                    text == value
+                } else {
+                    text.eq_ignore_ascii_case(value)
+                }
+            }
+            RegexOrText::Regex(regex) => regex.is_match(value),
+        }
+    }
+}